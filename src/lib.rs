@@ -1,5 +1,13 @@
 #![warn(clippy::pedantic, clippy::nursery)]
 
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
 use nix::{
     sys::time::TimeSpec,
     time::{clock_gettime, ClockId},
@@ -23,10 +31,24 @@ pub const SEC_IN_NANOS: u64 = 1_000_000_000;
 pub const TIME_MASK: u64 = ((1 << TIME_BITS) - 1) << COUNTER_BITS;
 pub const GENERATION_IN_POSITION: u64 = CURRENT_GENERATION << (TIME_BITS + COUNTER_BITS);
 
+/// Sentinel meaning "no stamp emitted yet". `CURRENT_GENERATION` leaves the
+/// top `GENERATION_BITS` of every real packed stamp unset, so the all-ones
+/// pattern can never be produced by `stamp_time`.
+const UNSET_STAMP: u64 = u64::MAX;
+
+pub const COUNTER_MASK: u64 = (1 << COUNTER_BITS) - 1;
+
+/// How many times `next()` will re-tick the underlying `TimeSource` looking
+/// for a newer time field before giving up on an exhausted counter.
+const MAX_COUNTER_SPINS: u32 = 1_000;
+
 #[derive(Debug)]
 pub enum Error {
     NixError(nix::errno::Errno),
     NegativeTimeSpec(TimeSpec),
+    /// All `COUNTER_BITS` of the counter were used within a single time
+    /// quantum and `TimeSource::tick()` never reported a newer time.
+    CounterExhausted,
 }
 
 impl From<nix::errno::Errno> for Error {
@@ -37,16 +59,80 @@ impl From<nix::errno::Errno> for Error {
 
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// Packs `secs`/`nanos` into the 42-bit time field.
+///
+/// Seconds are counted from `SECOND_EPOCH` at `TIME_SHIFT_BITS` worth of
+/// sub-second resolution. `nanos` is folded in as a fraction of that
+/// resolution rather than added directly, so the two quantities stay
+/// independently recoverable (see `Stamp::inst`) instead of being summed
+/// into one ambiguous number.
+///
+/// `secs` MUST already be anchored to the Unix epoch and at least
+/// `SECOND_EPOCH` (e.g. `CLOCK_REALTIME`, not `CLOCK_BOOTTIME`/
+/// `CLOCK_MONOTONIC`, whose arbitrary origin is usually far smaller and
+/// underflows the subtraction below).
 #[must_use]
 pub const fn stamp_time(secs: u64, nanos: u64) -> u64 {
-    let shifted_secs = secs << TIME_SHIFT_BITS;
-    let shifted_nanos = nanos << TIME_SHIFT_BITS;
-    let in_position = (shifted_secs + (shifted_nanos - SEC_IN_NANOS)) << COUNTER_BITS;
+    debug_assert!(secs >= SECOND_EPOCH, "stamp_time: secs must be Unix-epoch-anchored");
+    let epoch_secs = secs - SECOND_EPOCH;
+    let frac = (nanos << TIME_SHIFT_BITS) / SEC_IN_NANOS;
+    let in_position = ((epoch_secs << TIME_SHIFT_BITS) | frac) << COUNTER_BITS;
     GENERATION_IN_POSITION | (in_position & TIME_MASK)
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Stamp(u64);
 
+impl Stamp {
+    #[must_use]
+    pub const fn raw(self) -> u64 {
+        self.0
+    }
+
+    #[must_use]
+    pub const fn generation(self) -> u64 {
+        self.0 >> (TIME_BITS + COUNTER_BITS)
+    }
+
+    #[must_use]
+    pub const fn time(self) -> u64 {
+        (self.0 & TIME_MASK) >> COUNTER_BITS
+    }
+
+    #[must_use]
+    pub const fn counter(self) -> u64 {
+        self.0 & COUNTER_MASK
+    }
+
+    /// Reconstructs the `Inst` this stamp's time field was taken from.
+    ///
+    /// This is the inverse of `stamp_time`'s packing. `secs` is recovered
+    /// exactly; `nanos` is only recoverable to the sub-second resolution
+    /// `TIME_SHIFT_BITS` affords, so the round trip is exact to within
+    /// `SEC_IN_NANOS >> TIME_SHIFT_BITS` nanoseconds.
+    #[must_use]
+    pub const fn inst(self) -> Inst {
+        let time = self.time();
+        let epoch_secs = time >> TIME_SHIFT_BITS;
+        let frac = time & ((1 << TIME_SHIFT_BITS) - 1);
+        let nanos = (frac * SEC_IN_NANOS) >> TIME_SHIFT_BITS;
+        Inst::new(epoch_secs + SECOND_EPOCH, nanos)
+    }
+}
+
+impl From<u64> for Stamp {
+    fn from(raw: u64) -> Self {
+        Self(raw)
+    }
+}
+
+impl From<Stamp> for u64 {
+    fn from(stamp: Stamp) -> Self {
+        stamp.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Inst {
     secs: u64,
     nanos: u64,
@@ -67,21 +153,156 @@ impl Inst {
     pub const fn stamp(self) -> Stamp {
         Stamp(stamp_time(self.secs, self.nanos))
     }
+
+    /// Adds `dur`, carrying into `secs` when `nanos` overflows a second.
+    /// Returns `None` on `secs` overflow.
+    #[must_use]
+    pub const fn checked_add(self, dur: Duration) -> Option<Self> {
+        let Some(mut secs) = self.secs.checked_add(dur.as_secs()) else {
+            return None;
+        };
+        let mut nanos = self.nanos + dur.subsec_nanos() as u64;
+        if nanos >= SEC_IN_NANOS {
+            nanos -= SEC_IN_NANOS;
+            let Some(carried) = secs.checked_add(1) else {
+                return None;
+            };
+            secs = carried;
+        }
+        Some(Self::new(secs, nanos))
+    }
+
+    /// Subtracts `dur`, borrowing from `secs` when `nanos` underflows.
+    /// Returns `None` on `secs` underflow.
+    #[must_use]
+    pub const fn checked_sub(self, dur: Duration) -> Option<Self> {
+        let Some(mut secs) = self.secs.checked_sub(dur.as_secs()) else {
+            return None;
+        };
+        let subsec = dur.subsec_nanos() as u64;
+        let nanos = if self.nanos < subsec {
+            let Some(borrowed) = secs.checked_sub(1) else {
+                return None;
+            };
+            secs = borrowed;
+            self.nanos + SEC_IN_NANOS - subsec
+        } else {
+            self.nanos - subsec
+        };
+        Some(Self::new(secs, nanos))
+    }
+
+    /// The elapsed `Duration` between `other` and `self`, borrowing a
+    /// second from `secs` when `self.nanos < other.nanos`.
+    ///
+    /// Saturates to `Duration::ZERO` if `self` is before `other` (mirroring
+    /// `std::time::Instant::duration_since`) instead of underflowing;
+    /// callers that rebase a steady clock reading onto a wall-clock anchor
+    /// (see `rebase`) may see `self < other` if the underlying clock steps
+    /// backward, e.g. `CLOCK_REALTIME` across an NTP correction.
+    #[must_use]
+    pub const fn duration_since(self, other: Self) -> Duration {
+        if self.secs < other.secs || (self.secs == other.secs && self.nanos < other.nanos) {
+            return Duration::ZERO;
+        }
+        let (secs, nanos) = if self.nanos < other.nanos {
+            (self.secs - other.secs - 1, self.nanos + SEC_IN_NANOS - other.nanos)
+        } else {
+            (self.secs - other.secs, self.nanos - other.nanos)
+        };
+        #[allow(clippy::cast_possible_truncation)]
+        Duration::new(secs, nanos as u32)
+    }
+
+    /// Total nanoseconds since the Unix epoch, wide enough to hold an
+    /// `OffsetClock` calibration offset without overflow.
+    #[allow(clippy::cast_lossless)]
+    const fn to_nanos128(self) -> i128 {
+        self.secs as i128 * SEC_IN_NANOS as i128 + self.nanos as i128
+    }
+
+    /// Inverse of `to_nanos128`.
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    const fn from_nanos128(nanos: i128) -> Self {
+        let secs = nanos.div_euclid(SEC_IN_NANOS as i128);
+        let rem = nanos.rem_euclid(SEC_IN_NANOS as i128);
+        Self::new(secs as u64, rem as u64)
+    }
 }
 
 pub trait TimeSource {
-    fn tick() -> Result<Inst>;
+    fn tick(&self) -> Result<Inst>;
 
     /// Gets a valid Inst or PANIC!
-    fn must_tick() -> Inst {
-        Self::tick().unwrap()
+    fn must_tick(&self) -> Inst {
+        self.tick().unwrap()
+    }
+}
+
+/// The clock ids `SelectableClock` can read from.
+///
+/// Mirrors the distinction miri's clock shim draws between a steady clock
+/// and one that tracks wall time: `Boottime` and `Monotonic` are steady with
+/// an arbitrary origin and are not stepped by `settime` or NTP (though
+/// `CLOCK_BOOTTIME` can still appear to step back across a suspend/resume
+/// on some platforms, see `AtomicClock::next`); `Realtime` is epoch-anchored but can step backward under NTP correction,
+/// so pair it with `AtomicClock`'s monotonizing layer if strict ordering
+/// matters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockSource {
+    /// Steady, suspend-aware monotonic time with an arbitrary origin.
+    Boottime,
+    /// Steady monotonic time with an arbitrary origin; unlike `Boottime`,
+    /// not guaranteed to account for time spent suspended.
+    Monotonic,
+    /// Wall-clock time anchored to the Unix epoch; affected by `settime`
+    /// and NTP, so it is not guaranteed to be non-decreasing.
+    Realtime,
+}
+
+impl ClockSource {
+    const fn clock_id(self) -> ClockId {
+        match self {
+            Self::Boottime => ClockId::CLOCK_BOOTTIME,
+            Self::Monotonic => ClockId::CLOCK_MONOTONIC,
+            Self::Realtime => ClockId::CLOCK_REALTIME,
+        }
+    }
+}
+
+fn tick_clock(id: ClockId) -> Result<Inst> {
+    let tspec = clock_gettime(id)?;
+    let secs = tspec.tv_sec();
+    let nanos = tspec.tv_nsec();
+    if secs < 0 || nanos < 0 {
+        return Err(Error::NegativeTimeSpec(tspec));
     }
+    #[allow(clippy::cast_sign_loss)]
+    Ok(Inst::new(secs as u64, nanos as u64))
+}
+
+/// Rebases a steady clock's `now` reading onto `anchor`'s wall-clock epoch,
+/// using the time elapsed since `init` (a reading from that same steady
+/// clock, taken when `anchor` was captured).
+///
+/// `CLOCK_BOOTTIME`/`CLOCK_MONOTONIC` have an arbitrary, usually
+/// near-zero, origin, which underflows `stamp_time`'s `secs - SECOND_EPOCH`
+/// if fed in directly. Rebasing onto a `CLOCK_REALTIME` anchor keeps the
+/// clock steady while making its readings safe to stamp.
+fn rebase(now: Inst, init: Inst, anchor: Inst) -> Inst {
+    anchor.checked_add(now.duration_since(init)).unwrap_or(anchor)
 }
 
-/// MonotonicClock clocks monotonically, yo.
+/// Monotonic `TimeSource` over `CLOCK_BOOTTIME`, rebased onto wall time (see
+/// `rebase`) so it stays safe to stamp despite `CLOCK_BOOTTIME` itself not
+/// being epoch-anchored.
 pub struct MonotonicClock {
-    /// The Inst at which this clack was initialized.
+    /// This clock's own reading taken at construction; later reads are
+    /// rebased relative to this.
     init: Inst,
+    /// Wall-clock reading taken at construction; `tick` reports this plus
+    /// elapsed boot-uptime.
+    anchor: Inst,
 }
 
 impl MonotonicClock {
@@ -90,28 +311,78 @@ impl MonotonicClock {
     }
 
     pub fn try_new() -> Result<Self> {
-        match Self::tick() {
-            Ok(init) => Ok(Self { init }),
-            Err(err) => Err(err),
-        }
+        let init = tick_clock(ClockId::CLOCK_BOOTTIME)?;
+        let anchor = tick_clock(ClockId::CLOCK_REALTIME)?;
+        Ok(Self { init, anchor })
     }
 }
 
 impl TimeSource for MonotonicClock {
-    fn tick() -> Result<Inst> {
-        let tspec = clock_gettime(ClockId::CLOCK_BOOTTIME)?;
-        let secs = tspec.tv_sec();
-        let nanos = tspec.tv_nsec();
-        if secs < 0 || nanos < 0 {
-            return Err(Error::NegativeTimeSpec(tspec));
-        }
-        #[allow(clippy::cast_sign_loss)]
-        Ok(Inst::new(secs as u64, nanos as u64))
+    fn tick(&self) -> Result<Inst> {
+        let now = tick_clock(ClockId::CLOCK_BOOTTIME)?;
+        Ok(rebase(now, self.init, self.anchor))
+    }
+}
+
+/// Wall-clock `TimeSource` over `CLOCK_REALTIME`, whose seconds are already
+/// epoch-anchored the way `SECOND_EPOCH` assumes. Pair with `AtomicClock` to
+/// keep stamps non-decreasing across NTP backward steps.
+pub struct RealtimeClock;
+
+impl RealtimeClock {
+    pub fn new() -> Self {
+        Self::try_new().unwrap()
+    }
+
+    pub fn try_new() -> Result<Self> {
+        tick_clock(ClockId::CLOCK_REALTIME)?;
+        Ok(Self)
+    }
+}
+
+impl TimeSource for RealtimeClock {
+    fn tick(&self) -> Result<Inst> {
+        tick_clock(ClockId::CLOCK_REALTIME)
+    }
+}
+
+/// A `TimeSource` that reads from a `ClockSource` chosen at construction
+/// time, for callers that want to pick the clock id at runtime (see
+/// `AtomicClock::with_clock_id`).
+///
+/// Like `MonotonicClock`, readings are rebased (see `rebase`) onto a
+/// wall-clock anchor taken at construction, so `Boottime`/`Monotonic`
+/// sources stay safe to stamp even though they aren't epoch-anchored on
+/// their own.
+pub struct SelectableClock {
+    id: ClockSource,
+    /// This source's own reading taken at construction; later reads are
+    /// rebased relative to this.
+    init: Inst,
+    /// Wall-clock reading taken at construction; `tick` reports this plus
+    /// elapsed time on `id`'s own clock.
+    anchor: Inst,
+}
+
+impl SelectableClock {
+    pub fn try_new(id: ClockSource) -> Result<Self> {
+        let init = tick_clock(id.clock_id())?;
+        let anchor = tick_clock(ClockId::CLOCK_REALTIME)?;
+        Ok(Self { id, init, anchor })
+    }
+}
+
+impl TimeSource for SelectableClock {
+    fn tick(&self) -> Result<Inst> {
+        let now = tick_clock(self.id.clock_id())?;
+        Ok(rebase(now, self.init, self.anchor))
     }
 }
 
 pub struct AtomicClock<T> {
     source: T,
+    /// Last packed stamp handed out, used to monotonize concurrent ticks.
+    last: AtomicU64,
 }
 
 impl AtomicClock<MonotonicClock> {
@@ -123,23 +394,173 @@ impl AtomicClock<MonotonicClock> {
     #[must_use]
     pub fn try_new() -> Result<Self> {
         match MonotonicClock::try_new() {
-            Ok(source) => Ok(Self { source }),
+            Ok(source) => Ok(Self {
+                source,
+                last: AtomicU64::new(UNSET_STAMP),
+            }),
             Err(err) => Err(err),
         }
     }
 }
 
+impl AtomicClock<SelectableClock> {
+    /// Builds an `AtomicClock` reading from the given `ClockSource`. The
+    /// monotonizing layer stays engaged regardless of source, so e.g.
+    /// `ClockSource::Realtime` can't emit backward stamps across an NTP
+    /// correction.
+    #[must_use]
+    pub fn with_clock_id(id: ClockSource) -> Self {
+        Self::try_with_clock_id(id).unwrap()
+    }
+
+    /// Fallible counterpart of `with_clock_id`.
+    pub fn try_with_clock_id(id: ClockSource) -> Result<Self> {
+        Ok(Self::with_source(SelectableClock::try_new(id)?))
+    }
+}
+
 impl<T: TimeSource> AtomicClock<T> {
     pub const fn with_source(source: T) -> Self {
-        Self { source }
+        Self {
+            source,
+            last: AtomicU64::new(UNSET_STAMP),
+        }
+    }
+
+    pub fn now(&self) -> Stamp {
+        self.try_now().unwrap()
+    }
+
+    pub fn try_now(&self) -> Result<Stamp> {
+        self.next()
+    }
+
+    /// Returns a unique, monotonically non-decreasing `Stamp`.
+    ///
+    /// Ticks that land in the same time quantum as the last emitted stamp,
+    /// or that move the time field backward (e.g. `CLOCK_BOOTTIME` stepping
+    /// back across a suspend/resume), bump the low `COUNTER_BITS` on the
+    /// last emitted time field instead of colliding or going backward.
+    /// Lock-free via a `compare_exchange` retry loop. Errors with
+    /// `Error::CounterExhausted` if the counter fills up within one
+    /// quantum and re-ticking never observes a newer time.
+    pub fn next(&self) -> Result<Stamp> {
+        let mut spins = 0u32;
+        loop {
+            let gt = self.source.tick()?.stamp().0;
+            let prev = self.last.load(Ordering::Acquire);
+
+            let raw = if prev == UNSET_STAMP {
+                gt
+            } else {
+                let prev_gt = prev & !COUNTER_MASK;
+                let forward = gt != prev_gt && gt.wrapping_sub(prev_gt) < 1 << 63;
+                if forward {
+                    gt
+                } else {
+                    let counter = (prev & COUNTER_MASK) + 1;
+                    if counter > COUNTER_MASK {
+                        spins += 1;
+                        if spins > MAX_COUNTER_SPINS {
+                            return Err(Error::CounterExhausted);
+                        }
+                        continue;
+                    }
+                    prev_gt | counter
+                }
+            };
+
+            if self
+                .last
+                .compare_exchange_weak(prev, raw, Ordering::AcqRel, Ordering::Acquire)
+                .is_ok()
+            {
+                return Ok(Stamp(raw));
+            }
+        }
+    }
+}
+
+/// Calibration state for an `OffsetClock`, protected by a single mutex
+/// since `offset` and `max_error` must always be read and updated together.
+struct Calibration {
+    /// Nanoseconds added to a local `Inst` to estimate global time.
+    offset: i128,
+    /// The error bound supplied at the last calibration, in nanoseconds
+    /// (alongside `offset`, in the same unit).
+    max_error: u64,
+    /// The local `Inst` the calibration was taken against, used to grow
+    /// `max_error` as local time elapses since then.
+    calibrated_at: Inst,
+}
+
+/// A `TimeSource` wrapper separating a free-running local clock from an
+/// estimated, disciplined "global" time.
+///
+/// Follows the approach byztime uses to distinguish a local reference from
+/// a calibrated estimate. Until `set_reference` is called, `now_with_error`
+/// reports zero offset and an error of `max_drift_ppm` times the whole local
+/// reading, since drift is counted from `Inst::zero()`.
+pub struct OffsetClock<T> {
+    source: T,
+    calibration: Mutex<Calibration>,
+    /// Maximum clock drift rate, in parts per million, used to grow the
+    /// error bound as local time elapses since the last calibration.
+    max_drift_ppm: u64,
+}
+
+impl<T: TimeSource> OffsetClock<T> {
+    #[must_use]
+    pub const fn new(source: T, max_drift_ppm: u64) -> Self {
+        Self {
+            source,
+            calibration: Mutex::new(Calibration {
+                offset: 0,
+                max_error: 0,
+                calibrated_at: Inst::zero(),
+            }),
+            max_drift_ppm,
+        }
+    }
+
+    /// Calibrates this clock from an external trusted timestamp: `local`
+    /// is what this clock read at the moment `global` (accurate to within
+    /// `error`) was observed.
+    pub fn set_reference(&self, local: Inst, global: Inst, error: Duration) {
+        let mut calibration = self.calibration.lock().unwrap();
+        calibration.offset = global.to_nanos128() - local.to_nanos128();
+        calibration.max_error = u64::try_from(error.as_nanos()).unwrap_or(u64::MAX);
+        calibration.calibrated_at = local;
     }
 
     pub fn now(&self) -> Inst {
         self.try_now().unwrap()
     }
 
+    /// The estimated global `Inst`: the local tick plus the calibrated
+    /// offset.
     pub fn try_now(&self) -> Result<Inst> {
-        T::tick()
+        let local = self.source.tick()?;
+        let offset = self.calibration.lock().unwrap().offset;
+        Ok(Inst::from_nanos128(local.to_nanos128() + offset))
+    }
+
+    /// The estimated global `Inst` alongside its accumulated uncertainty:
+    /// the error bound set at the last calibration, plus `max_drift_ppm`
+    /// times the local time elapsed since then.
+    #[must_use]
+    pub fn now_with_error(&self) -> (Inst, Duration) {
+        let local = self.source.must_tick();
+        let (offset, max_error, calibrated_at) = {
+            let calibration = self.calibration.lock().unwrap();
+            (calibration.offset, calibration.max_error, calibration.calibrated_at)
+        };
+        let elapsed = local.duration_since(calibrated_at);
+        let drift_nanos = elapsed.as_nanos() * u128::from(self.max_drift_ppm) / 1_000_000;
+        let total_nanos = u128::from(max_error).saturating_add(drift_nanos);
+        let error = Duration::from_nanos(u64::try_from(total_nanos).unwrap_or(u64::MAX));
+        let global = Inst::from_nanos128(local.to_nanos128() + offset);
+        (global, error)
     }
 }
 
@@ -149,4 +570,210 @@ mod tests {
 
     #[test]
     fn it_works() {}
+
+    #[test]
+    fn stamp_raw_round_trips_through_u64() {
+        for raw in [0, 1, 42, GENERATION_IN_POSITION, u64::MAX >> GENERATION_BITS] {
+            let stamp = Stamp::from(raw);
+            assert_eq!(u64::from(stamp), raw);
+        }
+    }
+
+    #[test]
+    fn stamp_field_extraction_matches_packing() {
+        let raw = stamp_time(1_700_000_000, 123_456_789) | 7;
+        let stamp = Stamp::from(raw);
+        assert_eq!(stamp.generation(), CURRENT_GENERATION);
+        assert_eq!(stamp.counter(), 7);
+        assert_eq!(stamp.raw(), raw);
+    }
+
+    #[test]
+    fn stamp_decodes_to_an_inst_within_precision() {
+        // `decode(encode(inst)) == inst` within the representable
+        // precision, across the range `stamp_time` can pack: right at
+        // `SECOND_EPOCH`, at the 42-bit time field's ceiling (`SECOND_EPOCH
+        // + 2^(TIME_BITS - TIME_SHIFT_BITS) - 1`), and with `nanos` right
+        // up against a full second.
+        let ceiling_secs = SECOND_EPOCH + (1 << (TIME_BITS - TIME_SHIFT_BITS)) - 1;
+        let cases = [
+            Inst::new(1_700_000_000, 500_000_000),
+            Inst::new(SECOND_EPOCH, 0),
+            Inst::new(SECOND_EPOCH, 123_456_789),
+            Inst::new(ceiling_secs, 0),
+            Inst::new(1_700_000_000, 999_999_999),
+        ];
+        let tolerance = SEC_IN_NANOS >> TIME_SHIFT_BITS;
+        for original in cases {
+            let decoded = original.stamp().inst();
+            assert_eq!(decoded.secs, original.secs, "secs mismatch for {original:?}");
+            assert!(
+                decoded.nanos.abs_diff(original.nanos) <= tolerance,
+                "nanos mismatch for {original:?}: decoded {decoded:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn selectable_clock_uses_the_chosen_clock_id() {
+        assert_eq!(ClockSource::Boottime.clock_id(), ClockId::CLOCK_BOOTTIME);
+        assert_eq!(ClockSource::Monotonic.clock_id(), ClockId::CLOCK_MONOTONIC);
+        assert_eq!(ClockSource::Realtime.clock_id(), ClockId::CLOCK_REALTIME);
+    }
+
+    #[test]
+    fn selectable_clock_rebases_steady_sources_onto_wall_time() {
+        // `Boottime`/`Monotonic` aren't epoch-anchored on their own; this
+        // pins down that `AtomicClock` still produces a sane, stampable
+        // wall-time reading for every source, not just `Realtime`.
+        for source in [ClockSource::Boottime, ClockSource::Monotonic, ClockSource::Realtime] {
+            let clock = AtomicClock::with_clock_id(source);
+            let stamp = clock.now();
+            let now_secs = tick_clock(ClockId::CLOCK_REALTIME).unwrap().secs;
+            assert!(stamp.inst().secs.abs_diff(now_secs) <= 1);
+        }
+    }
+
+    #[test]
+    fn atomic_clock_default_constructor_stamps_without_underflow() {
+        // Regression test: `AtomicClock::<MonotonicClock>::new()` used to
+        // feed raw `CLOCK_BOOTTIME` seconds (usually far smaller than
+        // `SECOND_EPOCH`) straight into `stamp_time`, underflowing on every
+        // call.
+        let clock = AtomicClock::<MonotonicClock>::new();
+        let stamp = clock.now();
+        let now_secs = tick_clock(ClockId::CLOCK_REALTIME).unwrap().secs;
+        assert!(stamp.inst().secs.abs_diff(now_secs) <= 1);
+    }
+
+    #[test]
+    fn offset_clock_applies_and_ages_a_calibration() {
+        // Every `FixedClock` value reads the same fn-local static, so the
+        // test can move time forward (and tick a separate `FixedClock` for
+        // `local`) while the clock under test owns its own value.
+        struct FixedClock;
+        static FIXED_NANOS: AtomicU64 = AtomicU64::new(0);
+        impl TimeSource for FixedClock {
+            fn tick(&self) -> Result<Inst> {
+                let n = FIXED_NANOS.load(Ordering::Relaxed);
+                Ok(Inst::new(n / SEC_IN_NANOS, n % SEC_IN_NANOS))
+            }
+        }
+
+        FIXED_NANOS.store(1_700_000_000 * SEC_IN_NANOS, Ordering::Relaxed);
+        let clock = OffsetClock::new(FixedClock, 100);
+        let local = FixedClock.must_tick();
+        let global = Inst::new(1_700_000_100, 0);
+        clock.set_reference(local, global, Duration::from_millis(5));
+        assert_eq!(clock.now(), global);
+
+        FIXED_NANOS.store(1_700_001_000 * SEC_IN_NANOS, Ordering::Relaxed);
+        let (estimated, error) = clock.now_with_error();
+        assert_eq!(estimated, Inst::new(1_700_001_100, 0));
+        assert!(error > Duration::from_millis(5));
+    }
+
+    #[test]
+    fn next_bumps_the_counter_within_one_quantum() {
+        // `FixedClock` reads a fn-local static, so every tick in this test
+        // lands in the same time quantum.
+        struct FixedClock;
+        static FIXED_NANOS: AtomicU64 = AtomicU64::new(0);
+        impl TimeSource for FixedClock {
+            fn tick(&self) -> Result<Inst> {
+                let n = FIXED_NANOS.load(Ordering::Relaxed);
+                Ok(Inst::new(n / SEC_IN_NANOS, n % SEC_IN_NANOS))
+            }
+        }
+
+        FIXED_NANOS.store(1_700_000_000 * SEC_IN_NANOS, Ordering::Relaxed);
+        let clock = AtomicClock::with_source(FixedClock);
+        let first = clock.next().unwrap();
+        let second = clock.next().unwrap();
+        let third = clock.next().unwrap();
+        assert_eq!(second.time(), first.time());
+        assert_eq!(third.time(), first.time());
+        assert_eq!(second.counter(), first.counter() + 1);
+        assert_eq!(third.counter(), second.counter() + 1);
+    }
+
+    #[test]
+    fn next_errors_with_counter_exhausted_once_the_quantum_fills_up() {
+        struct FixedClock;
+        static FIXED_NANOS: AtomicU64 = AtomicU64::new(0);
+        impl TimeSource for FixedClock {
+            fn tick(&self) -> Result<Inst> {
+                let n = FIXED_NANOS.load(Ordering::Relaxed);
+                Ok(Inst::new(n / SEC_IN_NANOS, n % SEC_IN_NANOS))
+            }
+        }
+
+        FIXED_NANOS.store(1_700_000_000 * SEC_IN_NANOS, Ordering::Relaxed);
+        let clock = AtomicClock::with_source(FixedClock);
+        for _ in 0..=COUNTER_MASK {
+            clock.next().unwrap();
+        }
+        assert!(matches!(clock.next(), Err(Error::CounterExhausted)));
+    }
+
+    #[test]
+    fn next_bumps_the_counter_on_a_backward_tick_instead_of_colliding() {
+        // Regression test: a `TimeSource` that reports one high timestamp
+        // followed by several lower ones (e.g. `CLOCK_BOOTTIME` stepping
+        // back across a suspend/resume) used to make `next()` reuse the
+        // same raw stamp verbatim on every subsequent call instead of
+        // bumping the counter, so three backward ticks in a row produced
+        // three colliding `Stamp`s.
+        struct SteppingClock;
+        static STEP: AtomicU64 = AtomicU64::new(0);
+        const NANOS: [u64; 4] = [
+            1_700_000_010 * SEC_IN_NANOS,
+            1_700_000_000 * SEC_IN_NANOS,
+            1_700_000_000 * SEC_IN_NANOS,
+            1_700_000_000 * SEC_IN_NANOS,
+        ];
+        impl TimeSource for SteppingClock {
+            fn tick(&self) -> Result<Inst> {
+                let step = STEP.fetch_add(1, Ordering::Relaxed);
+                let n = NANOS[usize::try_from(step).unwrap_or(NANOS.len() - 1).min(NANOS.len() - 1)];
+                Ok(Inst::new(n / SEC_IN_NANOS, n % SEC_IN_NANOS))
+            }
+        }
+
+        let clock = AtomicClock::with_source(SteppingClock);
+        let high = clock.next().unwrap();
+        let low1 = clock.next().unwrap();
+        let low2 = clock.next().unwrap();
+        let low3 = clock.next().unwrap();
+
+        assert_eq!(low1.time(), high.time());
+        assert_eq!(low2.time(), high.time());
+        assert_eq!(low3.time(), high.time());
+        assert_ne!(low1, low2);
+        assert_ne!(low2, low3);
+        assert_eq!(low1.counter(), high.counter() + 1);
+        assert_eq!(low2.counter(), low1.counter() + 1);
+        assert_eq!(low3.counter(), low2.counter() + 1);
+    }
+
+    #[test]
+    fn duration_since_saturates_when_self_is_before_other() {
+        // Regression test: a rebased steady clock (see `rebase`) can see
+        // `self < other` if its underlying source steps backward, which
+        // used to underflow this subtraction instead of saturating like
+        // `std::time::Instant::duration_since`.
+        let earlier = Inst::new(100, 0);
+        let later = Inst::new(200, 500);
+        assert_eq!(earlier.duration_since(later), Duration::ZERO);
+        assert_eq!(Inst::new(100, 0).duration_since(Inst::new(100, 1)), Duration::ZERO);
+    }
+
+    #[test]
+    fn inst_checked_add_and_sub_round_trip() {
+        let start = Inst::new(1_700_000_000, 900_000_000);
+        let later = start.checked_add(Duration::new(5, 200_000_000)).unwrap();
+        assert_eq!(later, Inst::new(1_700_000_006, 100_000_000));
+        assert_eq!(later.checked_sub(Duration::new(5, 200_000_000)).unwrap(), start);
+        assert_eq!(later.duration_since(start), Duration::new(5, 200_000_000));
+    }
 }